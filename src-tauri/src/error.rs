@@ -0,0 +1,55 @@
+use serde::{Serialize, Serializer};
+
+/// Structured error type for `#[tauri::command]` handlers.
+///
+/// Commands used to return `Result<_, String>` built from ad-hoc `format!`
+/// calls, which threw away the error's provenance. Returning this type
+/// instead lets the frontend match on a `kind` field (see `Serialize` impl)
+/// and show actionable messages instead of an opaque string.
+#[derive(Debug, thiserror::Error)]
+pub enum CommandError {
+    #[error("I/O error: {0}")]
+    Io(#[from] std::io::Error),
+
+    #[error("network error: {0}")]
+    Network(#[from] reqwest::Error),
+
+    #[error("config error: {0}")]
+    Config(String),
+
+    #[error("installation error: {0}")]
+    Installation(String),
+
+    #[error("tauri error: {0}")]
+    Tauri(#[from] tauri::Error),
+
+    #[error("invalid path: {0}")]
+    InvalidPath(String),
+}
+
+/// Serialized as `{ "kind": "...", "message": "..." }` so the frontend can
+/// branch on `kind` instead of parsing the display string.
+impl Serialize for CommandError {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        use serde::ser::SerializeStruct;
+
+        let kind = match self {
+            CommandError::Io(_) => "io",
+            CommandError::Network(_) => "network",
+            CommandError::Config(_) => "config",
+            CommandError::Installation(_) => "installation",
+            CommandError::Tauri(_) => "tauri",
+            CommandError::InvalidPath(_) => "invalid_path",
+        };
+
+        let mut state = serializer.serialize_struct("CommandError", 2)?;
+        state.serialize_field("kind", kind)?;
+        state.serialize_field("message", &self.to_string())?;
+        state.end()
+    }
+}
+
+pub type CommandResult<T> = Result<T, CommandError>;
@@ -1,7 +1,9 @@
 use base64::{Engine as _, engine::general_purpose::STANDARD as BASE64_STANDARD};
 use serde::{Deserialize, Serialize};
-use std::fs::{self, File, OpenOptions};
-use std::io::{self, BufRead, BufReader, Cursor, Read, Write};
+use sha2::{Digest, Sha256};
+use std::collections::HashMap;
+use std::fs::{self, File};
+use std::io::{self, BufRead, BufReader, Cursor, Read};
 use std::path::{Path, PathBuf};
 use std::process::{Child, Command, Stdio};
 use std::sync::Mutex;
@@ -12,7 +14,17 @@ use flate2::read::GzDecoder;
 #[cfg(not(target_os = "windows"))]
 use tar::Archive;
 
+mod error;
+mod logging;
+mod protocol;
+mod sandbox;
+
+use error::{CommandError, CommandResult};
+
+static ENGINE_LOGGER: logging::EngineLogger = logging::EngineLogger::new();
+
 const CONFIG_FILENAME: &str = "config.json";
+const CONFIG_SCHEMA_FILENAME: &str = "config.schema.json";
 const WORLD_ENGINE_DIR: &str = "world_engine";
 const SEEDS_DIR: &str = "seeds";
 const UV_VERSION: &str = "0.9.26";
@@ -24,7 +36,7 @@ const SERVER_PY: &str = include_str!("../server-components/server.py");
 const PYPROJECT_TOML: &str = include_str!("../server-components/pyproject.toml");
 
 /// Engine mode: how the World Engine server should be managed
-#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default, schemars::JsonSchema)]
 #[serde(rename_all = "snake_case")]
 pub enum EngineMode {
     /// User hasn't chosen yet - show the choice dialog
@@ -36,18 +48,43 @@ pub enum EngineMode {
     Server,
 }
 
-// Global state for tracking the running server process
+// State for a single running engine server, keyed by port in the registry below.
 #[derive(Default)]
-struct ServerState {
+struct ServerInstance {
     process: Option<Child>,
-    port: Option<u16>,
     ready: bool,
 }
 
-static SERVER_STATE: std::sync::OnceLock<Mutex<ServerState>> = std::sync::OnceLock::new();
+// Registry of running engine servers, keyed by port, so more than one engine
+// (e.g. a fast preview model alongside a high-quality one) can run at once.
+static SERVER_REGISTRY: std::sync::OnceLock<Mutex<HashMap<u16, ServerInstance>>> =
+    std::sync::OnceLock::new();
+
+fn get_server_registry() -> &'static Mutex<HashMap<u16, ServerInstance>> {
+    SERVER_REGISTRY.get_or_init(|| Mutex::new(HashMap::new()))
+}
+
+// Remote engines registered via `register_remote_engine`, so the app can act
+// as a thin client over a server running on another host.
+static REMOTE_ENGINES: std::sync::OnceLock<Mutex<Vec<EngineEndpoint>>> = std::sync::OnceLock::new();
+
+fn get_remote_engines() -> &'static Mutex<Vec<EngineEndpoint>> {
+    REMOTE_ENGINES.get_or_init(|| Mutex::new(Vec::new()))
+}
+
+/// Build an HTTP client for talking to a remote engine, optionally skipping
+/// TLS verification for LAN boxes with a cert the OS trust store doesn't know.
+fn build_remote_client(accept_invalid_certs: bool) -> CommandResult<reqwest::Client> {
+    Ok(reqwest::Client::builder()
+        .danger_accept_invalid_certs(accept_invalid_certs)
+        .build()?)
+}
+
+// Tracks the in-flight `uv sync` child process so `cancel_setup` can kill it.
+static SETUP_PROCESS: std::sync::OnceLock<Mutex<Option<Child>>> = std::sync::OnceLock::new();
 
-fn get_server_state() -> &'static Mutex<ServerState> {
-    SERVER_STATE.get_or_init(|| Mutex::new(ServerState::default()))
+fn get_setup_process_slot() -> &'static Mutex<Option<Child>> {
+    SETUP_PROCESS.get_or_init(|| Mutex::new(None))
 }
 
 // Global app handle for emitting events from threads
@@ -74,37 +111,76 @@ fn new_command<S: AsRef<std::ffi::OsStr>>(program: S) -> Command {
     cmd
 }
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone, schemars::JsonSchema)]
 pub struct GpuServerConfig {
     pub host: String,
     pub port: u16,
     pub use_ssl: bool,
 }
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone, schemars::JsonSchema)]
 pub struct ApiKeysConfig {
     pub openai: String,
     pub fal: String,
     #[serde(default)]
     pub huggingface: String,
+    /// Bearer token sent with requests to a registered remote engine, if any.
+    #[serde(default)]
+    pub remote_engine_bearer_token: String,
+    /// Accept self-signed/invalid TLS certs when talking to a remote engine.
+    /// Only meant for LAN boxes with a cert the OS trust store doesn't know.
+    #[serde(default)]
+    pub remote_engine_accept_invalid_certs: bool,
+}
+
+/// Where a World Engine instance can be reached: a local subprocess this app
+/// spawns and owns, or a server already running elsewhere that we drive over
+/// HTTP(S) as a thin client.
+#[derive(Debug, Serialize, Deserialize, Clone, schemars::JsonSchema)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum EngineEndpoint {
+    Local {
+        port: u16,
+    },
+    Remote {
+        base_url: String,
+        #[serde(default)]
+        bearer_token: Option<String>,
+        #[serde(default)]
+        accept_invalid_certs: bool,
+    },
 }
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone, schemars::JsonSchema)]
 pub struct FeaturesConfig {
     pub prompt_sanitizer: bool,
     pub seed_generation: bool,
     pub engine_mode: EngineMode,
     #[serde(default)]
     pub seed_gallery: bool,
+    /// Managed Python version uv should install and run the World Engine
+    /// under (e.g. "3.12"). Pinning this keeps every install on the same
+    /// interpreter instead of letting uv pick whatever it finds.
+    #[serde(default = "default_python_version")]
+    pub python_version: Option<String>,
+    /// Run the World Engine subprocess inside a restricted namespace
+    /// (Linux only; a no-op elsewhere). Opt-in since it adds startup latency
+    /// and requires unprivileged user namespaces to be enabled on the host.
+    #[serde(default)]
+    pub sandbox_engine: bool,
 }
 
-#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+fn default_python_version() -> Option<String> {
+    Some("3.12".to_string())
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default, schemars::JsonSchema)]
 pub struct UiConfig {
     #[serde(default)]
     pub bottom_panel_hidden: bool,
 }
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone, schemars::JsonSchema)]
 pub struct AppConfig {
     pub gpu_server: GpuServerConfig,
     pub api_keys: ApiKeysConfig,
@@ -125,49 +201,78 @@ impl Default for AppConfig {
                 openai: String::new(),
                 fal: String::new(),
                 huggingface: String::new(),
+                remote_engine_bearer_token: String::new(),
+                remote_engine_accept_invalid_certs: false,
             },
             features: FeaturesConfig {
                 prompt_sanitizer: true,
                 seed_generation: true,
                 engine_mode: EngineMode::Unchosen,
                 seed_gallery: false,
+                python_version: default_python_version(),
+                sandbox_engine: false,
             },
             ui: UiConfig::default(),
         }
     }
 }
 
-fn get_config_path(app: &tauri::AppHandle) -> Result<PathBuf, String> {
-    let config_dir = app
-        .path()
-        .app_config_dir()
-        .map_err(|e| format!("Failed to get app config dir: {}", e))?;
+fn get_config_path(app: &tauri::AppHandle) -> CommandResult<PathBuf> {
+    let config_dir = app.path().app_config_dir()?;
 
     // Create config directory if it doesn't exist
     if !config_dir.exists() {
-        fs::create_dir_all(&config_dir)
-            .map_err(|e| format!("Failed to create config dir: {}", e))?;
+        fs::create_dir_all(&config_dir)?;
     }
 
     Ok(config_dir.join(CONFIG_FILENAME))
 }
 
+fn get_schema_path(app: &tauri::AppHandle) -> CommandResult<PathBuf> {
+    let config_dir = app.path().app_config_dir()?;
+    Ok(config_dir.join(CONFIG_SCHEMA_FILENAME))
+}
+
+/// (Re)generate `config.schema.json` next to `config.json` from `AppConfig`'s
+/// derived `JsonSchema`, so the hand-edited config is self-documenting.
+fn write_config_schema(app: &tauri::AppHandle) -> CommandResult<()> {
+    let schema = schemars::schema_for!(AppConfig);
+    let json = serde_json::to_string_pretty(&schema)
+        .map_err(|e| CommandError::Config(format!("Failed to serialize config schema: {}", e)))?;
+    fs::write(get_schema_path(app)?, json)?;
+    Ok(())
+}
+
+/// Serialize `config` with a `$schema` reference injected so editors can
+/// offer validation/autocomplete against `config.schema.json`.
+fn config_to_pretty_json(config: &AppConfig) -> CommandResult<String> {
+    let mut value = serde_json::to_value(config)
+        .map_err(|e| CommandError::Config(format!("Failed to serialize config: {}", e)))?;
+
+    if let Some(obj) = value.as_object_mut() {
+        obj.insert(
+            "$schema".to_string(),
+            serde_json::json!(format!("./{}", CONFIG_SCHEMA_FILENAME)),
+        );
+    }
+
+    serde_json::to_string_pretty(&value)
+        .map_err(|e| CommandError::Config(format!("Failed to serialize config: {}", e)))
+}
+
 #[tauri::command]
-fn read_config(app: tauri::AppHandle) -> Result<AppConfig, String> {
+fn read_config(app: tauri::AppHandle) -> CommandResult<AppConfig> {
     let config_path = get_config_path(&app)?;
 
     if !config_path.exists() {
         // Create default config file
         let default_config = AppConfig::default();
-        let json = serde_json::to_string_pretty(&default_config)
-            .map_err(|e| format!("Failed to serialize default config: {}", e))?;
-        fs::write(&config_path, json)
-            .map_err(|e| format!("Failed to write default config: {}", e))?;
+        fs::write(&config_path, config_to_pretty_json(&default_config)?)?;
+        write_config_schema(&app)?;
         return Ok(default_config);
     }
 
-    let content = fs::read_to_string(&config_path)
-        .map_err(|e| format!("Failed to read config file: {}", e))?;
+    let content = fs::read_to_string(&config_path)?;
 
     // Try parsing as new format first
     if let Ok(config) = serde_json::from_str::<AppConfig>(&content) {
@@ -176,7 +281,7 @@ fn read_config(app: tauri::AppHandle) -> Result<AppConfig, String> {
 
     // Try parsing as JSON Value to check for legacy format and migrate
     let mut json_value: serde_json::Value = serde_json::from_str(&content)
-        .map_err(|e| format!("Failed to parse config file: {}", e))?;
+        .map_err(|e| CommandError::Config(format!("Failed to parse config file: {}", e)))?;
 
     // Check for legacy use_standalone_engine boolean and migrate to engine_mode
     if let Some(features) = json_value.get_mut("features")
@@ -191,84 +296,125 @@ fn read_config(app: tauri::AppHandle) -> Result<AppConfig, String> {
         };
         features_obj.insert("engine_mode".to_string(), serde_json::json!(engine_mode));
 
-        // Save migrated config
-        let migrated_json = serde_json::to_string_pretty(&json_value)
-            .map_err(|e| format!("Failed to serialize migrated config: {}", e))?;
-        fs::write(&config_path, &migrated_json)
-            .map_err(|e| format!("Failed to write migrated config: {}", e))?;
-
-        println!(
-            "[CONFIG] Migrated use_standalone_engine to engine_mode: {}",
+        log::info!(
+            "Migrated use_standalone_engine to engine_mode: {}",
             engine_mode
         );
     }
 
     // Now parse the (potentially migrated) JSON as AppConfig
-    serde_json::from_value(json_value).map_err(|e| format!("Failed to parse config file: {}", e))
+    let config: AppConfig = serde_json::from_value(json_value)
+        .map_err(|e| CommandError::Config(format!("Failed to parse config file: {}", e)))?;
+
+    // Persist through the same helpers `write_config` uses, so a migrated
+    // legacy config also gets a `$schema` pointer and an on-disk
+    // config.schema.json instead of only picking those up on the next
+    // explicit `write_config` call.
+    fs::write(&config_path, config_to_pretty_json(&config)?)?;
+    write_config_schema(&app)?;
+
+    Ok(config)
+}
+
+#[tauri::command]
+fn write_config(app: tauri::AppHandle, config: AppConfig) -> CommandResult<()> {
+    let config_path = get_config_path(&app)?;
+
+    fs::write(&config_path, config_to_pretty_json(&config)?)?;
+    write_config_schema(&app)?;
+
+    Ok(())
+}
+
+/// A single schema violation in a user-edited `config.json`.
+#[derive(Debug, Serialize, Clone)]
+pub struct ConfigValidationIssue {
+    /// JSON pointer to the offending field, e.g. "/features/engine_mode".
+    pub path: String,
+    pub message: String,
 }
 
+/// Validate `config.json` against the schema derived from `AppConfig`,
+/// reporting the exact path and reason for any invalid field instead of
+/// silently falling back to defaults the way `read_config` does on legacy
+/// migration.
 #[tauri::command]
-fn write_config(app: tauri::AppHandle, config: AppConfig) -> Result<(), String> {
+async fn validate_config(app: tauri::AppHandle) -> CommandResult<Vec<ConfigValidationIssue>> {
     let config_path = get_config_path(&app)?;
 
-    let json = serde_json::to_string_pretty(&config)
-        .map_err(|e| format!("Failed to serialize config: {}", e))?;
+    if !config_path.exists() {
+        return Ok(Vec::new());
+    }
+
+    let content = fs::read_to_string(&config_path)?;
+    let instance: serde_json::Value = serde_json::from_str(&content)
+        .map_err(|e| CommandError::Config(format!("Failed to parse config file: {}", e)))?;
+
+    let schema = serde_json::to_value(schemars::schema_for!(AppConfig))
+        .map_err(|e| CommandError::Config(format!("Failed to serialize config schema: {}", e)))?;
+
+    let compiled = jsonschema::JSONSchema::compile(&schema)
+        .map_err(|e| CommandError::Config(format!("Failed to compile config schema: {}", e)))?;
 
-    fs::write(&config_path, json).map_err(|e| format!("Failed to write config file: {}", e))
+    let issues = match compiled.validate(&instance) {
+        Ok(()) => Vec::new(),
+        Err(errors) => errors
+            .map(|e| ConfigValidationIssue {
+                path: e.instance_path.to_string(),
+                message: e.to_string(),
+            })
+            .collect(),
+    };
+
+    Ok(issues)
 }
 
 #[tauri::command]
-fn get_config_path_str(app: tauri::AppHandle) -> Result<String, String> {
+fn get_config_path_str(app: tauri::AppHandle) -> CommandResult<String> {
     let config_path = get_config_path(&app)?;
     Ok(config_path.to_string_lossy().to_string())
 }
 
 #[tauri::command]
-async fn open_config(app: tauri::AppHandle) -> Result<(), String> {
+async fn open_config(app: tauri::AppHandle) -> CommandResult<()> {
     let config_path = get_config_path(&app)?;
 
     // Ensure config file exists before opening
     if !config_path.exists() {
         // Create default config if it doesn't exist
         let default_config = AppConfig::default();
-        let json = serde_json::to_string_pretty(&default_config)
-            .map_err(|e| format!("Failed to serialize default config: {}", e))?;
-        fs::write(&config_path, json)
-            .map_err(|e| format!("Failed to write default config: {}", e))?;
+        let json = config_to_pretty_json(&default_config)?;
+        fs::write(&config_path, json)?;
+        write_config_schema(&app)?;
     }
 
     // Open File Explorer with config file selected
-    tauri_plugin_opener::reveal_item_in_dir(config_path)
-        .map_err(|e| format!("Failed to reveal config file: {}", e))
+    tauri_plugin_opener::reveal_item_in_dir(config_path).map_err(|e| {
+        CommandError::Config(format!("Failed to reveal config file: {}", e))
+    })
 }
 
 // Get the engine directory path (inside app data dir)
-fn get_engine_dir(app: &tauri::AppHandle) -> Result<PathBuf, String> {
-    let data_dir = app
-        .path()
-        .app_data_dir()
-        .map_err(|e| format!("Failed to get app data dir: {}", e))?;
+fn get_engine_dir(app: &tauri::AppHandle) -> CommandResult<PathBuf> {
+    let data_dir = app.path().app_data_dir()?;
 
     // Create data directory if it doesn't exist
     if !data_dir.exists() {
-        fs::create_dir_all(&data_dir).map_err(|e| format!("Failed to create data dir: {}", e))?;
+        fs::create_dir_all(&data_dir)?;
     }
 
     Ok(data_dir.join(WORLD_ENGINE_DIR))
 }
 
 // Get the .uv directory path for isolated uv installation
-fn get_uv_dir(app: &tauri::AppHandle) -> Result<PathBuf, String> {
-    let data_dir = app
-        .path()
-        .app_data_dir()
-        .map_err(|e| format!("Failed to get app data dir: {}", e))?;
+fn get_uv_dir(app: &tauri::AppHandle) -> CommandResult<PathBuf> {
+    let data_dir = app.path().app_data_dir()?;
 
     Ok(data_dir.join(".uv"))
 }
 
 // Get the path to our local uv binary
-fn get_uv_binary_path(app: &tauri::AppHandle) -> Result<PathBuf, String> {
+fn get_uv_binary_path(app: &tauri::AppHandle) -> CommandResult<PathBuf> {
     let uv_dir = get_uv_dir(app)?;
     let bin_dir = uv_dir.join("bin");
 
@@ -292,10 +438,14 @@ pub struct EngineStatus {
     pub server_running: bool,
     pub server_port: Option<u16>,
     pub server_log_path: String,
+    /// Path to the resolved Python interpreter inside the synced `.venv`, if any.
+    pub python_path: Option<String>,
+    /// Version string reported by that interpreter (e.g. "Python 3.12.3").
+    pub python_version: Option<String>,
 }
 
 #[tauri::command]
-async fn check_engine_status(app: tauri::AppHandle) -> Result<EngineStatus, String> {
+async fn check_engine_status(app: tauri::AppHandle) -> CommandResult<EngineStatus> {
     let engine_dir = get_engine_dir(&app)?;
     let uv_binary = get_uv_binary_path(&app)?;
     let uv_dir = get_uv_dir(&app)?;
@@ -315,7 +465,9 @@ async fn check_engine_status(app: tauri::AppHandle) -> Result<EngineStatus, Stri
 
     // Check if dependencies are synced by verifying .venv exists and has a working Python
     // This catches cases where sync failed partway through
-    let dependencies_synced = if repo_cloned && engine_dir.join(".venv").exists() {
+    let (dependencies_synced, python_path, python_version) = if repo_cloned
+        && engine_dir.join(".venv").exists()
+    {
         // Verify the venv has a working Python interpreter
         #[cfg(target_os = "windows")]
         let python_path = engine_dir.join(".venv").join("Scripts").join("python.exe");
@@ -323,8 +475,8 @@ async fn check_engine_status(app: tauri::AppHandle) -> Result<EngineStatus, Stri
         let python_path = engine_dir.join(".venv").join("bin").join("python");
 
         if python_path.exists() {
-            // Try to run the Python interpreter to verify it works
-            new_command(&uv_binary)
+            // Try to run the Python interpreter to verify it works and report its version
+            let output = new_command(&uv_binary)
                 .current_dir(&engine_dir)
                 .arg("run")
                 .arg("python")
@@ -336,22 +488,34 @@ async fn check_engine_status(app: tauri::AppHandle) -> Result<EngineStatus, Stri
                 .env("UV_PYTHON_BIN_DIR", uv_dir.join("python_bin"))
                 .env("UV_TOOL_DIR", uv_dir.join("tool"))
                 .env("UV_TOOL_BIN_DIR", uv_dir.join("tool_bin"))
-                .output()
-                .map(|o| o.status.success())
-                .unwrap_or(false)
+                .output();
+
+            match output {
+                Ok(o) if o.status.success() => {
+                    let version = String::from_utf8_lossy(&o.stdout).trim().to_string();
+                    (
+                        true,
+                        Some(python_path.to_string_lossy().to_string()),
+                        Some(version),
+                    )
+                }
+                _ => (false, None, None),
+            }
         } else {
-            false
+            (false, None, None)
         }
     } else {
-        false
+        (false, None, None)
     };
 
-    // Check if server is running
+    // Check if any registered server is running
     let (server_running, server_port) = {
-        let state = get_server_state().lock().unwrap();
-        let running = state.process.is_some();
-        let port = state.port;
-        (running, port)
+        let registry = get_server_registry().lock().unwrap();
+        registry
+            .iter()
+            .find(|(_, instance)| instance.process.is_some())
+            .map(|(port, _)| (true, Some(*port)))
+            .unwrap_or((false, None))
     };
 
     let server_log_path = engine_dir.join("server.log").to_string_lossy().to_string();
@@ -364,16 +528,52 @@ async fn check_engine_status(app: tauri::AppHandle) -> Result<EngineStatus, Stri
         server_running,
         server_port,
         server_log_path,
+        python_path,
+        python_version,
     })
 }
 
+/// Page through `server.log`, returning up to `limit` lines starting at `offset`.
 #[tauri::command]
-async fn install_uv(app: tauri::AppHandle) -> Result<String, String> {
+async fn read_engine_log(
+    app: tauri::AppHandle,
+    offset: Option<usize>,
+    limit: Option<usize>,
+) -> CommandResult<Vec<String>> {
+    let engine_dir = get_engine_dir(&app)?;
+    let log_path = engine_dir.join("server.log");
+
+    if !log_path.exists() {
+        return Ok(Vec::new());
+    }
+
+    let content = fs::read_to_string(&log_path)?;
+
+    let offset = offset.unwrap_or(0);
+    let limit = limit.unwrap_or(500);
+
+    Ok(content
+        .lines()
+        .skip(offset)
+        .take(limit)
+        .map(|line| line.to_string())
+        .collect())
+}
+
+/// Payload for the `uv-download-progress` event emitted while streaming the uv archive.
+#[derive(Debug, Serialize, Clone)]
+struct DownloadProgress {
+    downloaded_bytes: u64,
+    total_bytes: Option<u64>,
+}
+
+#[tauri::command]
+async fn install_uv(app: tauri::AppHandle) -> CommandResult<String> {
     let uv_dir = get_uv_dir(&app)?;
     let bin_dir = uv_dir.join("bin");
 
     // Create bin directory
-    fs::create_dir_all(&bin_dir).map_err(|e| format!("Failed to create uv bin dir: {}", e))?;
+    fs::create_dir_all(&bin_dir)?;
 
     // Determine the download URL based on platform and architecture
     let (archive_name, _binary_name) = get_uv_archive_info();
@@ -382,19 +582,36 @@ async fn install_uv(app: tauri::AppHandle) -> Result<String, String> {
         UV_VERSION, archive_name
     );
 
-    // Download using async reqwest
-    let response = reqwest::get(&download_url)
-        .await
-        .map_err(|e| format!("Failed to download uv: {}", e))?;
+    // Download using async reqwest, streaming chunks so we can report progress
+    // instead of blocking the UI behind a single multi-hundred-megabyte read.
+    let mut response = reqwest::get(&download_url).await?;
 
     if !response.status().is_success() {
-        return Err(format!("Failed to download uv: HTTP {}", response.status()));
+        return Err(CommandError::Network(
+            response.error_for_status().unwrap_err(),
+        ));
     }
 
-    let bytes = response
-        .bytes()
-        .await
-        .map_err(|e| format!("Failed to read response: {}", e))?;
+    let total_bytes = response.content_length();
+    let mut bytes = Vec::with_capacity(total_bytes.unwrap_or(0) as usize);
+
+    while let Some(chunk) = response.chunk().await? {
+        bytes.extend_from_slice(&chunk);
+
+        if let Some(app) = get_app_handle() {
+            let _ = app.emit(
+                "uv-download-progress",
+                DownloadProgress {
+                    downloaded_bytes: bytes.len() as u64,
+                    total_bytes,
+                },
+            );
+        }
+    }
+
+    // Verify the download against the published SHA-256 before extracting anything,
+    // so a corrupted or tampered archive never reaches disk.
+    verify_uv_checksum(&uv_checksum_url(archive_name), &bytes).await?;
 
     // Extract based on platform
     #[cfg(target_os = "windows")]
@@ -410,6 +627,86 @@ async fn install_uv(app: tauri::AppHandle) -> Result<String, String> {
     Ok(format!("uv {} installed successfully", UV_VERSION))
 }
 
+// Build the sidecar checksum URL for a given release archive, e.g.
+// ".../uv-x86_64-unknown-linux-gnu.tar.gz.sha256"
+fn uv_checksum_url(archive_name: &str) -> String {
+    format!(
+        "https://github.com/astral-sh/uv/releases/download/{}/{}.sha256",
+        UV_VERSION, archive_name
+    )
+}
+
+/// Fetch the `<archive>.sha256` sidecar file and verify it matches the
+/// downloaded bytes. Each uv release asset ships a sidecar whose first
+/// whitespace-delimited token is the lowercase hex digest.
+async fn verify_uv_checksum(checksum_url: &str, bytes: &[u8]) -> CommandResult<()> {
+    let response = reqwest::get(checksum_url).await?;
+
+    if !response.status().is_success() {
+        return Err(CommandError::Network(response.error_for_status().unwrap_err()));
+    }
+
+    let body = response.text().await?;
+    check_checksum_sidecar(&body, bytes)
+}
+
+/// Compare `bytes`' SHA-256 digest against the first whitespace-delimited
+/// token of a checksum sidecar's contents. Split out from
+/// [`verify_uv_checksum`] so the comparison itself - the security-sensitive
+/// part - can be exercised without a network round trip.
+fn check_checksum_sidecar(sidecar_contents: &str, bytes: &[u8]) -> CommandResult<()> {
+    let expected = sidecar_contents
+        .split_whitespace()
+        .next()
+        .ok_or_else(|| CommandError::Installation("uv checksum file was empty".to_string()))?
+        .to_lowercase();
+
+    let mut hasher = Sha256::new();
+    hasher.update(bytes);
+    let actual = hex::encode(hasher.finalize());
+
+    if actual != expected {
+        return Err(CommandError::Installation(format!(
+            "uv download failed checksum verification (expected {}, got {})",
+            expected, actual
+        )));
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod checksum_tests {
+    use super::*;
+
+    #[test]
+    fn matching_checksum_passes() {
+        let bytes = b"uv release archive contents";
+        let digest = {
+            let mut hasher = Sha256::new();
+            hasher.update(bytes);
+            hex::encode(hasher.finalize())
+        };
+
+        assert!(check_checksum_sidecar(&digest, bytes).is_ok());
+    }
+
+    #[test]
+    fn mismatched_checksum_is_rejected() {
+        let bytes = b"uv release archive contents";
+        let wrong_digest = "0".repeat(64);
+
+        let err = check_checksum_sidecar(&wrong_digest, bytes).unwrap_err();
+        assert!(matches!(err, CommandError::Installation(_)));
+    }
+
+    #[test]
+    fn empty_sidecar_is_rejected() {
+        let err = check_checksum_sidecar("", b"anything").unwrap_err();
+        assert!(matches!(err, CommandError::Installation(_)));
+    }
+}
+
 // Get the archive name and binary name based on platform
 fn get_uv_archive_info() -> (&'static str, &'static str) {
     #[cfg(all(target_os = "windows", target_arch = "x86_64"))]
@@ -443,27 +740,86 @@ fn get_uv_archive_info() -> (&'static str, &'static str) {
     }
 }
 
+/// The uv binary for this platform, embedded at compile time when the
+/// `bundled-uv` feature is enabled. Mirrors the `cfg(target_os/target_arch)`
+/// matrix in `get_uv_archive_info` so the bundled binary always matches what
+/// `install_uv` would otherwise have downloaded.
+#[cfg(feature = "bundled-uv")]
+fn bundled_uv_binary() -> &'static [u8] {
+    #[cfg(all(target_os = "windows", target_arch = "x86_64"))]
+    {
+        include_bytes!("../bundled-uv/uv-x86_64-pc-windows-msvc.exe")
+    }
+
+    #[cfg(all(target_os = "windows", target_arch = "aarch64"))]
+    {
+        include_bytes!("../bundled-uv/uv-aarch64-pc-windows-msvc.exe")
+    }
+
+    #[cfg(all(target_os = "macos", target_arch = "x86_64"))]
+    {
+        include_bytes!("../bundled-uv/uv-x86_64-apple-darwin")
+    }
+
+    #[cfg(all(target_os = "macos", target_arch = "aarch64"))]
+    {
+        include_bytes!("../bundled-uv/uv-aarch64-apple-darwin")
+    }
+
+    #[cfg(all(target_os = "linux", target_arch = "x86_64"))]
+    {
+        include_bytes!("../bundled-uv/uv-x86_64-unknown-linux-gnu")
+    }
+
+    #[cfg(all(target_os = "linux", target_arch = "aarch64"))]
+    {
+        include_bytes!("../bundled-uv/uv-aarch64-unknown-linux-gnu")
+    }
+}
+
+/// Write the embedded uv binary to `get_uv_binary_path` and mark it
+/// executable on Unix. Only available for the platform/arch pairs covered by
+/// `bundled_uv_binary`.
+#[cfg(feature = "bundled-uv")]
+fn install_bundled_uv(app: &tauri::AppHandle) -> CommandResult<()> {
+    let uv_binary_path = get_uv_binary_path(app)?;
+
+    if let Some(parent) = uv_binary_path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+
+    fs::write(&uv_binary_path, bundled_uv_binary())?;
+
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        let mut perms = fs::metadata(&uv_binary_path)?.permissions();
+        perms.set_mode(0o755);
+        fs::set_permissions(&uv_binary_path, perms)?;
+    }
+
+    Ok(())
+}
+
 #[cfg(target_os = "windows")]
-fn extract_zip(bytes: &[u8], _uv_dir: &Path, bin_dir: &Path) -> Result<(), String> {
+fn extract_zip(bytes: &[u8], _uv_dir: &Path, bin_dir: &Path) -> CommandResult<()> {
     let cursor = Cursor::new(bytes);
-    let mut archive =
-        zip::ZipArchive::new(cursor).map_err(|e| format!("Failed to read zip archive: {}", e))?;
+    let mut archive = zip::ZipArchive::new(cursor)
+        .map_err(|e| CommandError::Installation(format!("Failed to read zip archive: {}", e)))?;
 
     for i in 0..archive.len() {
         let mut file = archive
             .by_index(i)
-            .map_err(|e| format!("Failed to read zip entry: {}", e))?;
+            .map_err(|e| CommandError::Installation(format!("Failed to read zip entry: {}", e)))?;
 
         let name = file.name().to_string();
 
         // We only care about uv.exe
         if name.ends_with("uv.exe") {
             let dest_path = bin_dir.join("uv.exe");
-            let mut dest_file =
-                File::create(&dest_path).map_err(|e| format!("Failed to create uv.exe: {}", e))?;
+            let mut dest_file = File::create(&dest_path)?;
 
-            io::copy(&mut file, &mut dest_file)
-                .map_err(|e| format!("Failed to write uv.exe: {}", e))?;
+            io::copy(&mut file, &mut dest_file)?;
 
             break;
         }
@@ -473,43 +829,33 @@ fn extract_zip(bytes: &[u8], _uv_dir: &Path, bin_dir: &Path) -> Result<(), Strin
 }
 
 #[cfg(not(target_os = "windows"))]
-fn extract_tar_gz(bytes: &[u8], _uv_dir: &Path, bin_dir: &Path) -> Result<(), String> {
+fn extract_tar_gz(bytes: &[u8], _uv_dir: &Path, bin_dir: &Path) -> CommandResult<()> {
     let cursor = Cursor::new(bytes);
     let gz = GzDecoder::new(cursor);
     let mut archive = Archive::new(gz);
 
-    let entries = archive
-        .entries()
-        .map_err(|e| format!("Failed to read tar archive: {}", e))?;
+    let entries = archive.entries()?;
 
     for entry in entries {
-        let mut entry = entry.map_err(|e| format!("Failed to read tar entry: {}", e))?;
-        let path = entry
-            .path()
-            .map_err(|e| format!("Failed to get entry path: {}", e))?;
+        let mut entry = entry?;
+        let path = entry.path()?;
 
         let path_str = path.to_string_lossy();
 
         // We only care about the uv binary (not uvx)
         if path_str.ends_with("/uv") && !path_str.ends_with("/uvx") {
             let dest_path = bin_dir.join("uv");
-            let mut dest_file = File::create(&dest_path)
-                .map_err(|e| format!("Failed to create uv binary: {}", e))?;
+            let mut dest_file = File::create(&dest_path)?;
 
-            io::copy(&mut entry, &mut dest_file)
-                .map_err(|e| format!("Failed to write uv binary: {}", e))?;
+            io::copy(&mut entry, &mut dest_file)?;
 
             // Make executable on Unix
             #[cfg(unix)]
             {
                 use std::os::unix::fs::PermissionsExt;
-                let mut perms = dest_file
-                    .metadata()
-                    .map_err(|e| format!("Failed to get metadata: {}", e))?
-                    .permissions();
+                let mut perms = dest_file.metadata()?.permissions();
                 perms.set_mode(0o755);
-                fs::set_permissions(&dest_path, perms)
-                    .map_err(|e| format!("Failed to set permissions: {}", e))?;
+                fs::set_permissions(&dest_path, perms)?;
             }
 
             break;
@@ -520,54 +866,91 @@ fn extract_tar_gz(bytes: &[u8], _uv_dir: &Path, bin_dir: &Path) -> Result<(), St
 }
 
 #[tauri::command]
-async fn setup_server_components(app: tauri::AppHandle) -> Result<String, String> {
+async fn setup_server_components(app: tauri::AppHandle) -> CommandResult<String> {
     let engine_dir = get_engine_dir(&app)?;
 
     // Create engine directory if it doesn't exist
-    fs::create_dir_all(&engine_dir).map_err(|e| format!("Failed to create engine dir: {}", e))?;
+    fs::create_dir_all(&engine_dir)?;
 
     // Write bundled server.py
-    fs::write(engine_dir.join("server.py"), SERVER_PY)
-        .map_err(|e| format!("Failed to write server.py: {}", e))?;
+    fs::write(engine_dir.join("server.py"), SERVER_PY)?;
 
     // Write bundled pyproject.toml
-    fs::write(engine_dir.join("pyproject.toml"), PYPROJECT_TOML)
-        .map_err(|e| format!("Failed to write pyproject.toml: {}", e))?;
+    fs::write(engine_dir.join("pyproject.toml"), PYPROJECT_TOML)?;
 
     Ok("Server components installed".to_string())
 }
 
 #[tauri::command]
-async fn sync_engine_dependencies(app: tauri::AppHandle) -> Result<String, String> {
+async fn sync_engine_dependencies(app: tauri::AppHandle) -> CommandResult<String> {
     let engine_dir = get_engine_dir(&app)?;
     let uv_dir = get_uv_dir(&app)?;
 
     if !engine_dir.exists() {
-        return Err("Engine repository not found. Please clone it first.".to_string());
+        return Err(CommandError::Installation(
+            "Engine repository not found. Please clone it first.".to_string(),
+        ));
     }
 
     // Create .uv directories
-    fs::create_dir_all(uv_dir.join("cache"))
-        .map_err(|e| format!("Failed to create uv cache dir: {}", e))?;
-    fs::create_dir_all(uv_dir.join("python_install"))
-        .map_err(|e| format!("Failed to create uv python_install dir: {}", e))?;
-    fs::create_dir_all(uv_dir.join("python_bin"))
-        .map_err(|e| format!("Failed to create uv python_bin dir: {}", e))?;
-    fs::create_dir_all(uv_dir.join("tool"))
-        .map_err(|e| format!("Failed to create uv tool dir: {}", e))?;
-    fs::create_dir_all(uv_dir.join("tool_bin"))
-        .map_err(|e| format!("Failed to create uv tool_bin dir: {}", e))?;
+    fs::create_dir_all(uv_dir.join("cache"))?;
+    fs::create_dir_all(uv_dir.join("python_install"))?;
+    fs::create_dir_all(uv_dir.join("python_bin"))?;
+    fs::create_dir_all(uv_dir.join("tool"))?;
+    fs::create_dir_all(uv_dir.join("tool_bin"))?;
 
     // Get our local uv binary path
     let uv_binary = get_uv_binary_path(&app)?;
 
     if !uv_binary.exists() {
-        return Err("uv is not installed. Please install it first.".to_string());
+        return Err(CommandError::Installation(
+            "uv is not installed. Please install it first.".to_string(),
+        ));
+    }
+
+    // Pin the managed Python version so every install resolves to the same
+    // interpreter instead of whatever uv happens to pick.
+    let config = read_config(app.clone()).unwrap_or_default();
+    let python_version = config.features.python_version;
+
+    if let Some(ref version) = python_version {
+        // `uv python install` can take a while the first time it downloads an
+        // interpreter - run it on a blocking-pool thread rather than calling
+        // `.output()` straight from this async worker.
+        let uv_binary_for_install = uv_binary.clone();
+        let uv_dir_for_install = uv_dir.clone();
+        let version_for_install = version.clone();
+        let install_output = tauri::async_runtime::spawn_blocking(move || {
+            new_command(&uv_binary_for_install)
+                .arg("python")
+                .arg("install")
+                .arg(&version_for_install)
+                .env("UV_CACHE_DIR", uv_dir_for_install.join("cache"))
+                .env(
+                    "UV_PYTHON_INSTALL_DIR",
+                    uv_dir_for_install.join("python_install"),
+                )
+                .env("UV_PYTHON_BIN_DIR", uv_dir_for_install.join("python_bin"))
+                .env("UV_TOOL_DIR", uv_dir_for_install.join("tool"))
+                .env("UV_TOOL_BIN_DIR", uv_dir_for_install.join("tool_bin"))
+                .output()
+        })
+        .await
+        .map_err(|e| CommandError::Installation(format!("uv python install task panicked: {}", e)))??;
+
+        if !install_output.status.success() {
+            return Err(CommandError::Installation(format!(
+                "uv python install {} failed: {}",
+                version,
+                String::from_utf8_lossy(&install_output.stderr)
+            )));
+        }
     }
 
     // Run uv sync with the specified environment variables
     // Note: Not using UV_FROZEN since we install world_engine from git without a lockfile
-    let output = new_command(&uv_binary)
+    let mut sync_cmd = new_command(&uv_binary);
+    sync_cmd
         .current_dir(&engine_dir)
         .arg("sync")
         .arg("--index-strategy")
@@ -579,26 +962,164 @@ async fn sync_engine_dependencies(app: tauri::AppHandle) -> Result<String, Strin
         .env("UV_PYTHON_INSTALL_DIR", uv_dir.join("python_install"))
         .env("UV_PYTHON_BIN_DIR", uv_dir.join("python_bin"))
         .env("UV_TOOL_DIR", uv_dir.join("tool"))
-        .env("UV_TOOL_BIN_DIR", uv_dir.join("tool_bin"))
-        .output()
-        .map_err(|e| format!("Failed to run uv sync: {}", e))?;
+        .env("UV_TOOL_BIN_DIR", uv_dir.join("tool_bin"));
 
-    if !output.status.success() {
-        return Err(format!(
+    if let Some(ref version) = python_version {
+        sync_cmd.arg("--python").arg(version);
+    }
+
+    // Spawn (rather than block on `.output()`) so we can tee stdout/stderr to
+    // `engine-sync-log` events as they arrive, and so `cancel_setup` has a
+    // live child to kill instead of a frozen multi-minute wait.
+    let mut child = sync_cmd
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()?;
+
+    let stdout = child.stdout.take();
+    let stderr = child.stderr.take();
+
+    let stdout_handle = stdout.map(|out| {
+        std::thread::spawn(move || {
+            let reader = BufReader::new(out);
+            for line in reader.lines().map_while(Result::ok) {
+                if let Some(app) = get_app_handle() {
+                    let _ = app.emit("engine-sync-log", &line);
+                }
+            }
+        })
+    });
+
+    let stderr_handle = stderr.map(|err| {
+        std::thread::spawn(move || {
+            let mut lines = Vec::new();
+            let reader = BufReader::new(err);
+            for line in reader.lines().map_while(Result::ok) {
+                if let Some(app) = get_app_handle() {
+                    let _ = app.emit("engine-sync-log", &line);
+                }
+                lines.push(line);
+            }
+            lines
+        })
+    });
+
+    *get_setup_process_slot().lock().unwrap() = Some(child);
+
+    // Poll for completion so a `cancel_setup` call can remove the child from
+    // the slot and kill it out from under this wait. `uv sync` runs for
+    // minutes, so the poll loop runs on a blocking-pool thread instead of
+    // parking this async worker for the duration.
+    let status = tauri::async_runtime::spawn_blocking(|| -> CommandResult<std::process::ExitStatus> {
+        loop {
+            let mut slot = get_setup_process_slot().lock().unwrap();
+            let Some(ref mut running) = *slot else {
+                return Err(CommandError::Installation(
+                    "uv sync was cancelled".to_string(),
+                ));
+            };
+            match running.try_wait()? {
+                Some(status) => {
+                    *slot = None;
+                    return Ok(status);
+                }
+                None => {
+                    drop(slot);
+                    std::thread::sleep(std::time::Duration::from_millis(100));
+                }
+            }
+        }
+    })
+    .await
+    .map_err(|e| CommandError::Installation(format!("uv sync wait task panicked: {}", e)))??;
+
+    if let Some(handle) = stdout_handle {
+        let _ = handle.join();
+    }
+    let stderr_lines = stderr_handle.and_then(|handle| handle.join().ok());
+
+    if !status.success() {
+        return Err(CommandError::Installation(format!(
             "uv sync failed: {}",
-            String::from_utf8_lossy(&output.stderr)
-        ));
+            stderr_lines.unwrap_or_default().join("\n")
+        )));
     }
 
     Ok("Dependencies synced successfully".to_string())
 }
 
+/// Kill an in-flight `uv sync` started by `sync_engine_dependencies`/`setup_engine`.
 #[tauri::command]
-async fn setup_engine(app: tauri::AppHandle) -> Result<String, String> {
-    // Step 1: Check/install uv
+async fn cancel_setup() -> CommandResult<String> {
+    let child = get_setup_process_slot().lock().unwrap().take();
+
+    match child {
+        Some(mut process) => {
+            let pid = process.id();
+            match kill_tree::blocking::kill_tree(pid) {
+                Ok(_) => {}
+                Err(_) => {
+                    let _ = process.kill();
+                }
+            }
+            let _ = process.wait();
+            Ok("Setup cancelled".to_string())
+        }
+        None => Err(CommandError::Installation(
+            "No setup is currently running".to_string(),
+        )),
+    }
+}
+
+/// Parse the pinned-able Python versions reported by `uv python list`.
+#[tauri::command]
+async fn list_available_pythons(app: tauri::AppHandle) -> CommandResult<Vec<String>> {
     let uv_binary = get_uv_binary_path(&app)?;
+    let uv_dir = get_uv_dir(&app)?;
 
     if !uv_binary.exists() {
+        return Err(CommandError::Installation(
+            "uv is not installed. Please install it first.".to_string(),
+        ));
+    }
+
+    let output = new_command(&uv_binary)
+        .arg("python")
+        .arg("list")
+        .env("UV_PYTHON_INSTALL_DIR", uv_dir.join("python_install"))
+        .env("UV_PYTHON_BIN_DIR", uv_dir.join("python_bin"))
+        .output()?;
+
+    if !output.status.success() {
+        return Err(CommandError::Installation(format!(
+            "uv python list failed: {}",
+            String::from_utf8_lossy(&output.stderr)
+        )));
+    }
+
+    // Each line looks like "cpython-3.12.3-linux-x86_64-gnu   <path>"; we only
+    // want the version, the second dash-separated field of the first token.
+    let versions = String::from_utf8_lossy(&output.stdout)
+        .lines()
+        .filter_map(|line| line.split_whitespace().next())
+        .filter_map(|entry| entry.split('-').nth(1).map(|v| v.to_string()))
+        .collect();
+
+    Ok(versions)
+}
+
+#[tauri::command]
+async fn setup_engine(app: tauri::AppHandle) -> CommandResult<String> {
+    // Step 1: Check/install uv. When built with `bundled-uv`, write the
+    // embedded binary straight to disk so setup works fully offline; only
+    // fall back to downloading it when the feature is off.
+    let uv_binary = get_uv_binary_path(&app)?;
+
+    if !uv_binary.exists() {
+        #[cfg(feature = "bundled-uv")]
+        install_bundled_uv(&app)?;
+
+        #[cfg(not(feature = "bundled-uv"))]
         install_uv(app.clone()).await?;
     }
 
@@ -614,11 +1135,11 @@ async fn setup_engine(app: tauri::AppHandle) -> Result<String, String> {
 /// Unpack bundled server files to the engine directory.
 /// If force is false, only unpacks files that don't already exist.
 /// If force is true, always overwrites existing files.
-fn unpack_server_files_inner(app: &tauri::AppHandle, force: bool) -> Result<String, String> {
+fn unpack_server_files_inner(app: &tauri::AppHandle, force: bool) -> CommandResult<String> {
     let engine_dir = get_engine_dir(app)?;
 
     // Create engine directory if it doesn't exist
-    fs::create_dir_all(&engine_dir).map_err(|e| format!("Failed to create engine dir: {}", e))?;
+    fs::create_dir_all(&engine_dir)?;
 
     let server_py_path = engine_dir.join("server.py");
     let pyproject_path = engine_dir.join("pyproject.toml");
@@ -627,14 +1148,12 @@ fn unpack_server_files_inner(app: &tauri::AppHandle, force: bool) -> Result<Stri
 
     // Only write if file doesn't exist OR force is true
     if force || !server_py_path.exists() {
-        fs::write(&server_py_path, SERVER_PY)
-            .map_err(|e| format!("Failed to write server.py: {}", e))?;
+        fs::write(&server_py_path, SERVER_PY)?;
         unpacked.push("server.py");
     }
 
     if force || !pyproject_path.exists() {
-        fs::write(&pyproject_path, PYPROJECT_TOML)
-            .map_err(|e| format!("Failed to write pyproject.toml: {}", e))?;
+        fs::write(&pyproject_path, PYPROJECT_TOML)?;
         unpacked.push("pyproject.toml");
     }
 
@@ -646,29 +1165,29 @@ fn unpack_server_files_inner(app: &tauri::AppHandle, force: bool) -> Result<Stri
 }
 
 #[tauri::command]
-async fn unpack_server_files(app: tauri::AppHandle, force: bool) -> Result<String, String> {
+async fn unpack_server_files(app: tauri::AppHandle, force: bool) -> CommandResult<String> {
     unpack_server_files_inner(&app, force)
 }
 
 #[tauri::command]
-fn get_engine_dir_path(app: tauri::AppHandle) -> Result<String, String> {
+fn get_engine_dir_path(app: tauri::AppHandle) -> CommandResult<String> {
     let engine_dir = get_engine_dir(&app)?;
     Ok(engine_dir.to_string_lossy().to_string())
 }
 
 #[tauri::command]
-async fn open_engine_dir(app: tauri::AppHandle) -> Result<(), String> {
+async fn open_engine_dir(app: tauri::AppHandle) -> CommandResult<()> {
     let engine_dir = get_engine_dir(&app)?;
 
     // Create directory if it doesn't exist
     if !engine_dir.exists() {
-        fs::create_dir_all(&engine_dir)
-            .map_err(|e| format!("Failed to create engine dir: {}", e))?;
+        fs::create_dir_all(&engine_dir)?;
     }
 
     // Open File Explorer with engine directory
-    tauri_plugin_opener::reveal_item_in_dir(engine_dir)
-        .map_err(|e| format!("Failed to open engine directory: {}", e))
+    tauri_plugin_opener::reveal_item_in_dir(engine_dir).map_err(|e| {
+        CommandError::Config(format!("Failed to open engine directory: {}", e))
+    })
 }
 
 // ============================================================================
@@ -676,30 +1195,30 @@ async fn open_engine_dir(app: tauri::AppHandle) -> Result<(), String> {
 // ============================================================================
 
 // Get the seeds directory path (inside app data dir)
-fn get_seeds_dir(app: &tauri::AppHandle) -> Result<PathBuf, String> {
+fn get_seeds_dir(app: &tauri::AppHandle) -> CommandResult<PathBuf> {
     let data_dir = app
         .path()
         .app_data_dir()
-        .map_err(|e| format!("Failed to get app data dir: {}", e))?;
+        .map_err(|e| CommandError::Config(format!("Failed to get app data dir: {}", e)))?;
 
     Ok(data_dir.join(SEEDS_DIR))
 }
 
 /// Initialize seeds by copying bundled seeds to app_data_dir/seeds/ on first run
 #[tauri::command]
-async fn initialize_seeds(app: tauri::AppHandle) -> Result<String, String> {
+async fn initialize_seeds(app: tauri::AppHandle) -> CommandResult<String> {
     let seeds_dir = get_seeds_dir(&app)?;
 
     // Create seeds directory if it doesn't exist
     if !seeds_dir.exists() {
-        fs::create_dir_all(&seeds_dir).map_err(|e| format!("Failed to create seeds dir: {}", e))?;
+        fs::create_dir_all(&seeds_dir)?;
     }
 
     // Get the resource path for bundled seeds (production)
     let resource_path = app
         .path()
         .resource_dir()
-        .map_err(|e| format!("Failed to get resource dir: {}", e))?;
+        .map_err(|e| CommandError::Config(format!("Failed to get resource dir: {}", e)))?;
 
     let bundled_seeds_dir = resource_path.join("seeds");
 
@@ -753,7 +1272,7 @@ async fn initialize_seeds(app: tauri::AppHandle) -> Result<String, String> {
 
 /// List available seed filenames (png/jpg/jpeg)
 #[tauri::command]
-async fn list_seeds(app: tauri::AppHandle) -> Result<Vec<String>, String> {
+async fn list_seeds(app: tauri::AppHandle) -> CommandResult<Vec<String>> {
     let seeds_dir = get_seeds_dir(&app)?;
 
     if !seeds_dir.exists() {
@@ -762,8 +1281,7 @@ async fn list_seeds(app: tauri::AppHandle) -> Result<Vec<String>, String> {
 
     let mut seeds = Vec::new();
 
-    let entries =
-        fs::read_dir(&seeds_dir).map_err(|e| format!("Failed to read seeds dir: {}", e))?;
+    let entries = fs::read_dir(&seeds_dir)?;
 
     for entry in entries.flatten() {
         let path = entry.path();
@@ -782,64 +1300,113 @@ async fn list_seeds(app: tauri::AppHandle) -> Result<Vec<String>, String> {
 
 /// Read a seed file and return base64 encoded data
 #[tauri::command]
-async fn read_seed_as_base64(app: tauri::AppHandle, filename: String) -> Result<String, String> {
+async fn read_seed_as_base64(app: tauri::AppHandle, filename: String) -> CommandResult<String> {
     let seeds_dir = get_seeds_dir(&app)?;
     let seed_path = seeds_dir.join(&filename);
 
     if !seed_path.exists() {
-        return Err(format!("Seed file not found: {}", filename));
+        return Err(CommandError::Config(format!(
+            "Seed file not found: {}",
+            filename
+        )));
     }
 
     // Validate that the file is within the seeds directory (prevent path traversal)
-    let canonical_seeds = seeds_dir
-        .canonicalize()
-        .map_err(|e| format!("Failed to canonicalize seeds dir: {}", e))?;
-    let canonical_seed = seed_path
-        .canonicalize()
-        .map_err(|e| format!("Failed to canonicalize seed path: {}", e))?;
+    let canonical_seeds = seeds_dir.canonicalize()?;
+    let canonical_seed = seed_path.canonicalize()?;
 
     if !canonical_seed.starts_with(&canonical_seeds) {
-        return Err("Invalid seed path".to_string());
+        return Err(CommandError::InvalidPath("Invalid seed path".to_string()));
     }
 
-    let mut file =
-        File::open(&seed_path).map_err(|e| format!("Failed to open seed file: {}", e))?;
+    let mut file = File::open(&seed_path)?;
 
     let mut buffer = Vec::new();
-    file.read_to_end(&mut buffer)
-        .map_err(|e| format!("Failed to read seed file: {}", e))?;
+    file.read_to_end(&mut buffer)?;
 
     Ok(BASE64_STANDARD.encode(&buffer))
 }
 
+/// Upload a local seed file to a remote engine's `/seeds` endpoint, so a
+/// thin-client setup can push assets to a server it doesn't share a
+/// filesystem with.
+#[tauri::command]
+async fn upload_seed_to_remote(
+    app: tauri::AppHandle,
+    filename: String,
+    endpoint: EngineEndpoint,
+) -> CommandResult<String> {
+    let EngineEndpoint::Remote {
+        base_url,
+        bearer_token,
+        accept_invalid_certs,
+    } = endpoint
+    else {
+        return Err(CommandError::Config(
+            "upload_seed_to_remote requires a Remote engine endpoint".to_string(),
+        ));
+    };
+
+    let seeds_dir = get_seeds_dir(&app)?;
+    let seed_path = seeds_dir.join(&filename);
+
+    // Validate that the file is within the seeds directory (prevent path traversal)
+    let canonical_seeds = seeds_dir.canonicalize()?;
+    let canonical_seed = seed_path.canonicalize()?;
+
+    if !canonical_seed.starts_with(&canonical_seeds) {
+        return Err(CommandError::InvalidPath("Invalid seed path".to_string()));
+    }
+
+    let bytes = fs::read(&seed_path)?;
+
+    let client = build_remote_client(accept_invalid_certs)?;
+    let url = format!("{}/seeds/{}", base_url.trim_end_matches('/'), filename);
+    let mut request = client.post(url).body(bytes);
+    if let Some(token) = bearer_token {
+        request = request.bearer_auth(token);
+    }
+
+    let response = request.send().await?;
+
+    if !response.status().is_success() {
+        return Err(CommandError::Config(format!(
+            "Remote engine rejected seed upload: {}",
+            response.status()
+        )));
+    }
+
+    Ok(format!("Uploaded {} to {}", filename, base_url))
+}
+
 /// Read a seed file and return a small thumbnail as base64 encoded JPEG
 #[tauri::command]
 async fn read_seed_thumbnail(
     app: tauri::AppHandle,
     filename: String,
     max_size: Option<u32>,
-) -> Result<String, String> {
+) -> CommandResult<String> {
     let seeds_dir = get_seeds_dir(&app)?;
     let seed_path = seeds_dir.join(&filename);
 
     if !seed_path.exists() {
-        return Err(format!("Seed file not found: {}", filename));
+        return Err(CommandError::Config(format!(
+            "Seed file not found: {}",
+            filename
+        )));
     }
 
     // Validate path
-    let canonical_seeds = seeds_dir
-        .canonicalize()
-        .map_err(|e| format!("Failed to canonicalize seeds dir: {}", e))?;
-    let canonical_seed = seed_path
-        .canonicalize()
-        .map_err(|e| format!("Failed to canonicalize seed path: {}", e))?;
+    let canonical_seeds = seeds_dir.canonicalize()?;
+    let canonical_seed = seed_path.canonicalize()?;
 
     if !canonical_seed.starts_with(&canonical_seeds) {
-        return Err("Invalid seed path".to_string());
+        return Err(CommandError::InvalidPath("Invalid seed path".to_string()));
     }
 
     // Load and resize image
-    let img = image::open(&seed_path).map_err(|e| format!("Failed to open image: {}", e))?;
+    let img = image::open(&seed_path)
+        .map_err(|e| CommandError::Config(format!("Failed to open image: {}", e)))?;
 
     let max_dim = max_size.unwrap_or(80);
     let thumbnail = img.thumbnail(max_dim, max_dim);
@@ -849,71 +1416,76 @@ async fn read_seed_thumbnail(
     let mut cursor = Cursor::new(&mut buffer);
     thumbnail
         .write_to(&mut cursor, image::ImageFormat::Jpeg)
-        .map_err(|e| format!("Failed to encode thumbnail: {}", e))?;
+        .map_err(|e| CommandError::Config(format!("Failed to encode thumbnail: {}", e)))?;
 
     Ok(BASE64_STANDARD.encode(&buffer))
 }
 
 /// Get the seeds directory path
 #[tauri::command]
-fn get_seeds_dir_path(app: tauri::AppHandle) -> Result<String, String> {
+fn get_seeds_dir_path(app: tauri::AppHandle) -> CommandResult<String> {
     let seeds_dir = get_seeds_dir(&app)?;
     Ok(seeds_dir.to_string_lossy().to_string())
 }
 
 /// Open the seeds directory in file explorer
 #[tauri::command]
-async fn open_seeds_dir(app: tauri::AppHandle) -> Result<(), String> {
+async fn open_seeds_dir(app: tauri::AppHandle) -> CommandResult<()> {
     let seeds_dir = get_seeds_dir(&app)?;
 
     // Create directory if it doesn't exist
     if !seeds_dir.exists() {
-        fs::create_dir_all(&seeds_dir).map_err(|e| format!("Failed to create seeds dir: {}", e))?;
+        fs::create_dir_all(&seeds_dir)?;
     }
 
     // Open File Explorer with seeds directory
-    tauri_plugin_opener::reveal_item_in_dir(seeds_dir)
-        .map_err(|e| format!("Failed to open seeds directory: {}", e))
+    tauri_plugin_opener::reveal_item_in_dir(seeds_dir).map_err(|e| {
+        CommandError::Config(format!("Failed to open seeds directory: {}", e))
+    })
 }
 
 #[tauri::command]
-async fn start_engine_server(app: tauri::AppHandle, port: u16) -> Result<String, String> {
+async fn start_engine_server(app: tauri::AppHandle, port: u16) -> CommandResult<String> {
     let engine_dir = get_engine_dir(&app)?;
     let uv_dir = get_uv_dir(&app)?;
     let uv_binary = get_uv_binary_path(&app)?;
 
-    // Check if server is already running
+    // Check if a server is already running on this port
     {
-        let state = get_server_state().lock().unwrap();
-        if state.process.is_some() {
-            return Err(format!(
+        let registry = get_server_registry().lock().unwrap();
+        if registry.get(&port).is_some_and(|i| i.process.is_some()) {
+            return Err(CommandError::Config(format!(
                 "Server is already running on port {}",
-                state.port.unwrap_or(0)
-            ));
+                port
+            )));
         }
     }
 
     // Verify dependencies are synced
     if !engine_dir.join(".venv").exists() {
-        return Err("Engine dependencies not synced. Please run setup first.".to_string());
+        return Err(CommandError::Installation(
+            "Engine dependencies not synced. Please run setup first.".to_string(),
+        ));
     }
 
     if !uv_binary.exists() {
-        return Err("uv is not installed. Please install it first.".to_string());
+        return Err(CommandError::Installation(
+            "uv is not installed. Please install it first.".to_string(),
+        ));
     }
 
     // Reset ready state
     {
-        let mut state = get_server_state().lock().unwrap();
-        state.ready = false;
+        let mut registry = get_server_registry().lock().unwrap();
+        registry.entry(port).or_default().ready = false;
     }
 
-    println!("[ENGINE] Starting server on port {}...", port);
-    println!("[ENGINE] Engine dir: {:?}", engine_dir);
-    println!("[ENGINE] UV binary: {:?}", uv_binary);
+    log::info!("Starting server on port {}...", port);
+    log::info!("Engine dir: {:?}", engine_dir);
+    log::info!("UV binary: {:?}", uv_binary);
 
     // Run uv sync to ensure dependencies are up to date
-    println!("[ENGINE] Syncing dependencies...");
+    log::info!("Syncing dependencies...");
     let sync_output = new_command(&uv_binary)
         .current_dir(&engine_dir)
         .arg("sync")
@@ -925,23 +1497,20 @@ async fn start_engine_server(app: tauri::AppHandle, port: u16) -> Result<String,
         .env("UV_PYTHON_BIN_DIR", uv_dir.join("python_bin"))
         .env("UV_TOOL_DIR", uv_dir.join("tool"))
         .env("UV_TOOL_BIN_DIR", uv_dir.join("tool_bin"))
-        .output()
-        .map_err(|e| format!("Failed to run uv sync: {}", e))?;
+        .output()?;
 
     if !sync_output.status.success() {
         let stderr = String::from_utf8_lossy(&sync_output.stderr);
-        println!("[ENGINE] Warning: uv sync failed: {}", stderr);
+        log::warn!("uv sync failed: {}", stderr);
         // Don't fail here - maybe deps are already synced
     } else {
-        println!("[ENGINE] Dependencies synced successfully");
+        log::info!("Dependencies synced successfully");
     }
 
-    // Create log file for server output
+    // Point the unified engine logger at this engine's server.log
     let log_file_path = engine_dir.join("server.log");
-    println!(
-        "[ENGINE] Server logs will be written to: {:?}",
-        log_file_path
-    );
+    ENGINE_LOGGER.set_log_path(log_file_path.clone());
+    log::info!("Server logs will be written to: {:?}", log_file_path);
 
     // Spawn the server process with piped stdout/stderr so we can tee to console and file
     // Command: uv run python server.py --port <port>
@@ -974,225 +1543,453 @@ async fn start_engine_server(app: tauri::AppHandle, port: u16) -> Result<String,
     };
 
     if let Some(token) = hf_token {
-        println!(
-            "[ENGINE] HuggingFace token configured ({}... chars)",
+        log::info!(
+            "HuggingFace token configured ({}... chars)",
             token.len().min(4)
         );
         cmd.env("HF_TOKEN", &token);
         cmd.env("HUGGING_FACE_HUB_TOKEN", &token);
     } else {
-        println!("[ENGINE] Warning: No HuggingFace token configured");
+        log::warn!("No HuggingFace token configured");
     }
 
-    let mut child = cmd
-        .spawn()
-        .map_err(|e| format!("Failed to start server: {}", e))?;
+    // Opt-in sandboxing (Linux only, no-op elsewhere): restricted namespaces,
+    // a reduced filesystem view, dropped capabilities, and a seccomp
+    // allow-list. `stop_server_sync`'s `kill_tree` still covers teardown,
+    // since it walks the host's process tree down from the spawned process.
+    #[cfg(target_os = "linux")]
+    if config.features.sandbox_engine {
+        use std::os::unix::process::CommandExt;
+        let sandbox_engine_dir = engine_dir.clone();
+        let sandbox_seeds_dir = get_seeds_dir(&app)?;
+        let sandbox_uv_dir = uv_dir.clone();
+        log::info!("Sandboxing engine process (port {})", port);
+        unsafe {
+            cmd.pre_exec(move || {
+                sandbox::isolate(&sandbox_engine_dir, &sandbox_seeds_dir, &sandbox_uv_dir)
+            });
+        }
+    }
 
-    let pid = child.id();
-    println!("[ENGINE] Server process spawned with PID: {}", pid);
+    let mut child = cmd.spawn()?;
 
-    // Set up tee: pipe stdout/stderr to both console and log file
-    let log_file_path_clone = log_file_path.clone();
+    let pid = child.id();
+    log::info!("Server process spawned with PID: {}", pid);
 
     // Take ownership of stdout/stderr from child
     let child_stdout = child.stdout.take();
     let child_stderr = child.stderr.take();
 
-    // Helper function to process log lines - emits events and detects server ready
+    // Helper function to process log lines. Every line is tee'd through the
+    // engine logger (which durably writes it to server.log) regardless of
+    // shape. A line carrying the `@@BIOME@@` sentinel is parsed as a
+    // structured `protocol::EngineEvent` and emitted under its own typed
+    // event instead of `server-log`, so the frontend gets reliable progress
+    // bars and error codes without string matching. Readiness is decided by
+    // the health-check poller below, not by anything printed here.
     fn process_log_line(line: &str, is_stderr: bool) {
-        // Print to console
         if is_stderr {
-            eprintln!("[SERVER] {}", line);
+            log::warn!("{}", line);
         } else {
-            println!("[SERVER] {}", line);
-        }
-
-        // Emit event to frontend
-        if let Some(app) = get_app_handle() {
-            let _ = app.emit("server-log", line);
+            log::info!("{}", line);
         }
 
-        // Check if server is ready (look for the ready message)
-        if line.contains("SERVER READY") || line.contains("Uvicorn running on") {
-            println!("[ENGINE] Server ready signal detected!");
-            let mut state = get_server_state().lock().unwrap();
-            state.ready = true;
-            // Emit ready event
+        if let Some(event) = protocol::parse_line(line) {
             if let Some(app) = get_app_handle() {
-                let _ = app.emit("server-ready", true);
+                let _ = app.emit(protocol::event_name(&event), &event);
             }
+            return;
+        }
+
+        // Emit raw line to frontend for the process console
+        if let Some(app) = get_app_handle() {
+            let _ = app.emit("server-log", line);
         }
     }
 
-    // Spawn thread to tee stdout to console, log file, and emit events
+    // Spawn thread to tee stdout to the engine logger and emit events
     if let Some(stdout) = child_stdout {
-        let log_path = log_file_path_clone.clone();
         std::thread::spawn(move || {
-            let mut log_file = OpenOptions::new()
-                .create(true)
-                .append(true)
-                .open(&log_path)
-                .ok();
-
             let reader = BufReader::new(stdout);
             for line in reader.lines().map_while(Result::ok) {
                 process_log_line(&line, false);
-                if let Some(ref mut file) = log_file {
-                    let _ = writeln!(file, "{}", line);
-                    let _ = file.flush();
-                }
             }
         });
     }
 
-    // Spawn thread to tee stderr to console, log file, and emit events
+    // Spawn thread to tee stderr to the engine logger and emit events
     if let Some(stderr) = child_stderr {
-        let log_path = log_file_path_clone;
         std::thread::spawn(move || {
-            let mut log_file = OpenOptions::new()
-                .create(true)
-                .append(true)
-                .open(&log_path)
-                .ok();
-
             let reader = BufReader::new(stderr);
             for line in reader.lines().map_while(Result::ok) {
                 process_log_line(&line, true);
-                if let Some(ref mut file) = log_file {
-                    let _ = writeln!(file, "{}", line);
-                    let _ = file.flush();
-                }
             }
         });
     }
 
     // Store the process handle
     {
-        let mut state = get_server_state().lock().unwrap();
-        state.process = Some(child);
-        state.port = Some(port);
+        let mut registry = get_server_registry().lock().unwrap();
+        registry.entry(port).or_default().process = Some(child);
     }
 
+    // Poll the health endpoint in the background instead of scraping stdout
+    // for a magic string - readiness now reflects what the server actually
+    // answers, not how Python happened to format its startup banner.
+    spawn_readiness_poller(port, log_file_path.clone());
+
     // Wait a moment and check if the process crashed immediately
     std::thread::sleep(std::time::Duration::from_millis(500));
 
     // Check if process is still running
     {
-        let mut state = get_server_state().lock().unwrap();
-        if let Some(ref mut process) = state.process {
-            match process.try_wait() {
-                Ok(Some(exit_status)) => {
-                    // Process exited - read the log file for error details
-                    state.process = None;
-                    state.port = None;
+        let mut registry = get_server_registry().lock().unwrap();
+        let status = registry
+            .get_mut(&port)
+            .and_then(|instance| instance.process.as_mut())
+            .map(|process| process.try_wait());
+
+        match status {
+            Some(Ok(Some(exit_status))) => {
+                // Process exited - read the log file for error details
+                registry.remove(&port);
+                drop(registry);
+
+                // Give the tee threads a moment to flush
+                std::thread::sleep(std::time::Duration::from_millis(100));
+
+                let log_contents = fs::read_to_string(&log_file_path)
+                    .unwrap_or_else(|_| "Unable to read log file".to_string());
+
+                // Extract the last part of the log (likely contains the error)
+                let error_excerpt: String = log_contents
+                    .lines()
+                    .rev()
+                    .take(30)
+                    .collect::<Vec<_>>()
+                    .into_iter()
+                    .rev()
+                    .collect::<Vec<_>>()
+                    .join("\n");
+
+                return Err(CommandError::Installation(format!(
+                    "Server process exited immediately with status: {}\n\nLast log output:\n{}",
+                    exit_status, error_excerpt
+                )));
+            }
+            Some(Ok(None)) => {
+                // Process is still running - good!
+                log::info!("Server process is running");
+            }
+            Some(Err(e)) => {
+                log::warn!("Could not check process status: {}", e);
+            }
+            None => {}
+        }
+    }
 
-                    // Give the tee threads a moment to flush
-                    std::thread::sleep(std::time::Duration::from_millis(100));
+    Ok(format!("Server started on port {} (PID: {})", port, pid))
+}
 
-                    let log_contents = fs::read_to_string(&log_file_path)
-                        .unwrap_or_else(|_| "Unable to read log file".to_string());
-
-                    // Extract the last part of the log (likely contains the error)
-                    let error_excerpt: String = log_contents
-                        .lines()
-                        .rev()
-                        .take(30)
-                        .collect::<Vec<_>>()
-                        .into_iter()
-                        .rev()
-                        .collect::<Vec<_>>()
-                        .join("\n");
-
-                    return Err(format!(
-                        "Server process exited immediately with status: {}\n\nLast log output:\n{}",
-                        exit_status, error_excerpt
-                    ));
+/// Polls the engine's `/health` endpoint until it answers with a success
+/// status, then sets `ready` on the port's registry entry and emits
+/// `server-ready`. Backs off
+/// exponentially (100ms doubling to a 2s cap) and gives up after about a
+/// minute, emitting `server-ready-failed` with the log tail so the UI can
+/// show the caller why startup stalled instead of spinning forever.
+fn spawn_readiness_poller(port: u16, log_file_path: PathBuf) {
+    std::thread::spawn(move || {
+        let client = reqwest::blocking::Client::new();
+        let url = format!("http://127.0.0.1:{}/health", port);
+        let deadline = std::time::Instant::now() + std::time::Duration::from_secs(60);
+        let mut backoff = std::time::Duration::from_millis(100);
+        let max_backoff = std::time::Duration::from_secs(2);
+
+        loop {
+            if let Ok(resp) = client.get(&url).send()
+                && resp.status().is_success()
+            {
+                if let Some(instance) = get_server_registry().lock().unwrap().get_mut(&port) {
+                    instance.ready = true;
                 }
-                Ok(None) => {
-                    // Process is still running - good!
-                    println!("[ENGINE] Server process is running");
+                log::info!("Server ready (health check succeeded on port {})", port);
+                if let Some(app) = get_app_handle() {
+                    let _ = app.emit("server-ready", true);
                 }
-                Err(e) => {
-                    println!("[ENGINE] Warning: Could not check process status: {}", e);
+                return;
+            }
+
+            if std::time::Instant::now() >= deadline {
+                log::warn!("Server did not become ready within 60s (port {})", port);
+                let log_contents = fs::read_to_string(&log_file_path)
+                    .unwrap_or_else(|_| "Unable to read log file".to_string());
+                let tail: String = log_contents
+                    .lines()
+                    .rev()
+                    .take(30)
+                    .collect::<Vec<_>>()
+                    .into_iter()
+                    .rev()
+                    .collect::<Vec<_>>()
+                    .join("\n");
+                if let Some(app) = get_app_handle() {
+                    let _ = app.emit("server-ready-failed", tail);
                 }
+                return;
             }
-        }
-    }
 
-    Ok(format!("Server started on port {} (PID: {})", port, pid))
+            std::thread::sleep(backoff);
+            backoff = std::cmp::min(backoff * 2, max_backoff);
+        }
+    });
 }
 
-// Core sync function to stop the server - used by both the command and cleanup
-fn stop_server_sync() -> Result<String, String> {
-    let mut state = get_server_state().lock().unwrap();
+// How long to wait for a SIGTERM'd process tree to exit on its own before
+// escalating to SIGKILL. Long enough for a checkpoint write to finish, short
+// enough that a wedged process doesn't hang shutdown indefinitely.
+const SHUTDOWN_GRACE_PERIOD: std::time::Duration = std::time::Duration::from_secs(10);
+const SHUTDOWN_POLL_INTERVAL: std::time::Duration = std::time::Duration::from_millis(200);
+
+// Core sync function to stop the server on `port` - used by both the command and cleanup
+fn stop_server_sync(port: u16) -> CommandResult<String> {
+    let mut registry = get_server_registry().lock().unwrap();
+    let Some(mut instance) = registry.remove(&port) else {
+        return Err(CommandError::Config(format!(
+            "No server is running on port {}",
+            port
+        )));
+    };
+    drop(registry);
 
-    if let Some(mut process) = state.process.take() {
-        let pid = process.id();
-        println!("[ENGINE] Stopping server process tree (PID: {})...", pid);
+    let Some(mut process) = instance.process.take() else {
+        return Err(CommandError::Config(format!(
+            "No server is currently running on port {}",
+            port
+        )));
+    };
 
-        // Kill entire process tree (handles uvicorn child processes)
+    let pid = process.id();
+    log::info!(
+        "Stopping server process tree (PID: {}) with SIGTERM...",
+        pid
+    );
+
+    // Ask the tree to shut down gracefully first - SIGKILL can corrupt an
+    // in-flight checkpoint write or leave GPU memory in a bad state.
+    let sigterm_sent = match kill_tree::blocking::kill_tree_with_config(
+        pid,
+        &kill_tree::Config {
+            signal: "SIGTERM".to_string(),
+            ..Default::default()
+        },
+    ) {
+        Ok(outputs) => {
+            log::info!("Sent SIGTERM to {} processes in tree", outputs.len());
+            true
+        }
+        Err(e) => {
+            log::warn!("Failed to send SIGTERM to process tree: {}", e);
+            false
+        }
+    };
+
+    let mut exited = false;
+    if sigterm_sent {
+        let deadline = std::time::Instant::now() + SHUTDOWN_GRACE_PERIOD;
+        while std::time::Instant::now() < deadline {
+            match process.try_wait() {
+                Ok(Some(_)) => {
+                    exited = true;
+                    break;
+                }
+                Ok(None) => std::thread::sleep(SHUTDOWN_POLL_INTERVAL),
+                Err(_) => break,
+            }
+        }
+    }
+
+    if !exited {
+        log::warn!(
+            "Server (PID: {}) did not exit within {}s of SIGTERM, escalating to SIGKILL",
+            pid,
+            SHUTDOWN_GRACE_PERIOD.as_secs()
+        );
         match kill_tree::blocking::kill_tree(pid) {
             Ok(outputs) => {
-                println!("[ENGINE] Killed {} processes in tree", outputs.len());
+                log::info!("Killed {} processes in tree", outputs.len());
             }
             Err(e) => {
-                println!(
-                    "[ENGINE] kill_tree failed, falling back to direct kill: {}",
-                    e
-                );
+                log::warn!("kill_tree failed, falling back to direct kill: {}", e);
                 let _ = process.kill();
             }
         }
-
-        // Wait for our direct child to fully terminate
         let _ = process.wait();
-        state.port = None;
-        state.ready = false;
-        println!("[ENGINE] Server stopped successfully");
-        Ok(format!("Server stopped (PID: {})", pid))
-    } else {
-        Err("No server is currently running".to_string())
+    }
+
+    log::info!("Server stopped successfully");
+    Ok(format!("Server stopped (PID: {})", pid))
+}
+
+// Stops every registered server - used on Ctrl+C/exit so no orphaned engine
+// is left running when the app itself goes away.
+fn stop_all_servers_sync() {
+    let ports: Vec<u16> = get_server_registry().lock().unwrap().keys().copied().collect();
+    for port in ports {
+        let _ = stop_server_sync(port);
     }
 }
 
 #[tauri::command]
-async fn stop_engine_server() -> Result<String, String> {
-    stop_server_sync()
+async fn stop_engine_server(port: u16) -> CommandResult<String> {
+    // `stop_server_sync` sleep-polls for up to `SHUTDOWN_GRACE_PERIOD` waiting
+    // on the child - run it on a blocking-pool thread instead of the async
+    // worker calling this command, which would otherwise park for up to 10s.
+    tauri::async_runtime::spawn_blocking(move || stop_server_sync(port))
+        .await
+        .map_err(|e| CommandError::Installation(format!("Shutdown task panicked: {}", e)))?
+}
+
+/// Hit a remote engine's `/health` endpoint and report whether it answered
+/// successfully. Used for both `is_server_running` and `is_server_ready`
+/// since a remote engine has no local `Child` to inspect - if it responds,
+/// it's both running and ready as far as this app is concerned.
+async fn check_remote_health(
+    base_url: &str,
+    bearer_token: &Option<String>,
+    accept_invalid_certs: bool,
+) -> bool {
+    let Ok(client) = build_remote_client(accept_invalid_certs) else {
+        return false;
+    };
+
+    let mut request = client.get(format!("{}/health", base_url.trim_end_matches('/')));
+    if let Some(token) = bearer_token {
+        request = request.bearer_auth(token);
+    }
+
+    request
+        .send()
+        .await
+        .map(|resp| resp.status().is_success())
+        .unwrap_or(false)
 }
 
 #[tauri::command]
-async fn is_server_running() -> Result<bool, String> {
-    let mut state = get_server_state().lock().unwrap();
-
-    if let Some(ref mut process) = state.process {
-        // Check if process is still running by trying to get its exit status
-        match process.try_wait() {
-            Ok(Some(_status)) => {
-                // Process has exited
-                state.process = None;
-                state.port = None;
-                Ok(false)
-            }
-            Ok(None) => {
-                // Process is still running
-                Ok(true)
-            }
-            Err(_) => {
-                // Error checking - assume not running
-                state.process = None;
-                state.port = None;
-                Ok(false)
+async fn is_server_running(endpoint: EngineEndpoint) -> CommandResult<bool> {
+    match endpoint {
+        EngineEndpoint::Local { port } => {
+            let mut registry = get_server_registry().lock().unwrap();
+            let Some(instance) = registry.get_mut(&port) else {
+                return Ok(false);
+            };
+
+            let Some(ref mut process) = instance.process else {
+                return Ok(false);
+            };
+
+            // Check if process is still running by trying to get its exit status
+            match process.try_wait() {
+                Ok(Some(_status)) => {
+                    // Process has exited
+                    registry.remove(&port);
+                    Ok(false)
+                }
+                Ok(None) => {
+                    // Process is still running
+                    Ok(true)
+                }
+                Err(_) => {
+                    // Error checking - assume not running
+                    registry.remove(&port);
+                    Ok(false)
+                }
             }
         }
-    } else {
-        Ok(false)
+        EngineEndpoint::Remote {
+            base_url,
+            bearer_token,
+            accept_invalid_certs,
+        } => Ok(check_remote_health(&base_url, &bearer_token, accept_invalid_certs).await),
+    }
+}
+
+#[tauri::command]
+async fn is_server_ready(endpoint: EngineEndpoint) -> bool {
+    match endpoint {
+        EngineEndpoint::Local { port } => {
+            let registry = get_server_registry().lock().unwrap();
+            registry.get(&port).is_some_and(|instance| instance.ready)
+        }
+        EngineEndpoint::Remote {
+            base_url,
+            bearer_token,
+            accept_invalid_certs,
+        } => check_remote_health(&base_url, &bearer_token, accept_invalid_certs).await,
     }
 }
 
+/// Register a server already running elsewhere so this app can drive it as a
+/// thin client instead of spawning a local subprocess. `bearer_token` and
+/// `accept_invalid_certs` default from `config.api_keys` when omitted, so
+/// setting them there actually takes effect instead of only the explicit
+/// command arguments mattering. Registering the same `base_url` again
+/// replaces the existing entry rather than accumulating a duplicate.
 #[tauri::command]
-fn is_server_ready() -> bool {
-    let state = get_server_state().lock().unwrap();
-    state.ready
+fn register_remote_engine(
+    app: tauri::AppHandle,
+    base_url: String,
+    bearer_token: Option<String>,
+    accept_invalid_certs: Option<bool>,
+) -> EngineEndpoint {
+    let config = read_config(app).unwrap_or_default();
+
+    let bearer_token = bearer_token.or_else(|| {
+        (!config.api_keys.remote_engine_bearer_token.is_empty())
+            .then(|| config.api_keys.remote_engine_bearer_token.clone())
+    });
+    let accept_invalid_certs =
+        accept_invalid_certs.unwrap_or(config.api_keys.remote_engine_accept_invalid_certs);
+
+    let endpoint = EngineEndpoint::Remote {
+        base_url: base_url.clone(),
+        bearer_token,
+        accept_invalid_certs,
+    };
+
+    let mut engines = get_remote_engines().lock().unwrap();
+    match engines
+        .iter_mut()
+        .find(|e| matches!(e, EngineEndpoint::Remote { base_url: existing, .. } if *existing == base_url))
+    {
+        Some(existing) => *existing = endpoint.clone(),
+        None => engines.push(endpoint.clone()),
+    }
+
+    endpoint
+}
+
+/// Remove a previously registered remote engine by its `base_url`, e.g. once
+/// the UI drops a stale or unreachable connection.
+#[tauri::command]
+fn unregister_remote_engine(base_url: String) {
+    get_remote_engines()
+        .lock()
+        .unwrap()
+        .retain(|e| !matches!(e, EngineEndpoint::Remote { base_url: existing, .. } if *existing == base_url));
+}
+
+/// List every known engine: local servers we've spawned (by port) plus any
+/// remote engines registered via `register_remote_engine`.
+#[tauri::command]
+fn list_engines() -> Vec<EngineEndpoint> {
+    let mut engines: Vec<EngineEndpoint> = get_server_registry()
+        .lock()
+        .unwrap()
+        .keys()
+        .map(|port| EngineEndpoint::Local { port: *port })
+        .collect();
+    engines.extend(get_remote_engines().lock().unwrap().iter().cloned());
+    engines
 }
 
 #[tauri::command]
@@ -1211,13 +2008,26 @@ pub fn run() {
             // Store app handle for event emission from threads
             set_app_handle(app.handle().clone());
 
-            // Set up Ctrl+C handler to stop the server on termination
+            // Route the `log` facade to the engine logger: tees every record to
+            // the frontend as `engine-log` and to `server.log` in the engine dir.
+            log::set_logger(&ENGINE_LOGGER)
+                .map(|()| log::set_max_level(log::LevelFilter::Info))
+                .expect("Error installing engine logger");
+            if let Ok(engine_dir) = get_engine_dir(app.handle()) {
+                ENGINE_LOGGER.set_log_path(engine_dir.join("server.log"));
+            }
+
+            // Stop every running server on termination. Built with the
+            // "termination" feature, `ctrlc` installs this handler for
+            // SIGINT, SIGTERM, and SIGHUP (not just Ctrl+C), so the engine is
+            // also shut down cleanly when the OS kills the app or a session
+            // ends, not only on an interactive Ctrl+C.
             ctrlc::set_handler(move || {
-                println!("[ENGINE] Received Ctrl+C, stopping server...");
-                let _ = stop_server_sync();
+                log::info!("Received termination signal, stopping server...");
+                stop_all_servers_sync();
                 std::process::exit(0);
             })
-            .expect("Error setting Ctrl+C handler");
+            .expect("Error setting termination signal handler");
 
             Ok(())
         })
@@ -1226,11 +2036,15 @@ pub fn run() {
             write_config,
             get_config_path_str,
             open_config,
+            validate_config,
             check_engine_status,
+            read_engine_log,
             install_uv,
             setup_server_components,
             sync_engine_dependencies,
             setup_engine,
+            cancel_setup,
+            list_available_pythons,
             unpack_server_files,
             get_engine_dir_path,
             open_engine_dir,
@@ -1239,9 +2053,13 @@ pub fn run() {
             is_server_running,
             is_server_ready,
             is_port_in_use,
+            register_remote_engine,
+            unregister_remote_engine,
+            list_engines,
             initialize_seeds,
             list_seeds,
             read_seed_as_base64,
+            upload_seed_to_remote,
             read_seed_thumbnail,
             get_seeds_dir_path,
             open_seeds_dir
@@ -1251,7 +2069,7 @@ pub fn run() {
 
     app.run(|_app_handle, event| {
         if let RunEvent::Exit = event {
-            let _ = stop_server_sync();
+            stop_all_servers_sync();
         }
     });
 }
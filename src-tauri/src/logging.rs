@@ -0,0 +1,54 @@
+use log::{Level, Log, Metadata, Record};
+use std::fs::OpenOptions;
+use std::io::Write;
+use std::path::PathBuf;
+use std::sync::Mutex;
+use tauri::Emitter;
+
+/// Tees every `log` record to the frontend as an `engine-log` event and to
+/// the `server.log` file `check_engine_status` advertises, so config
+/// migrations, uv installs, and sync failures all land in one durable record
+/// instead of vanishing into a console the bundled GUI app doesn't have.
+pub struct EngineLogger {
+    log_path: Mutex<Option<PathBuf>>,
+}
+
+impl EngineLogger {
+    pub const fn new() -> Self {
+        Self {
+            log_path: Mutex::new(None),
+        }
+    }
+
+    /// Point the logger at `server.log` inside the engine directory. Safe to
+    /// call again if the app data dir changes.
+    pub fn set_log_path(&self, path: PathBuf) {
+        *self.log_path.lock().unwrap() = Some(path);
+    }
+}
+
+impl Log for EngineLogger {
+    fn enabled(&self, metadata: &Metadata) -> bool {
+        metadata.level() <= Level::Info
+    }
+
+    fn log(&self, record: &Record) {
+        if !self.enabled(record.metadata()) {
+            return;
+        }
+
+        let line = format!("[{}] {}", record.level(), record.args());
+
+        if let Some(app) = crate::get_app_handle() {
+            let _ = app.emit("engine-log", &line);
+        }
+
+        if let Some(path) = self.log_path.lock().unwrap().as_ref()
+            && let Ok(mut file) = OpenOptions::new().create(true).append(true).open(path)
+        {
+            let _ = writeln!(file, "{}", line);
+        }
+    }
+
+    fn flush(&self) {}
+}
@@ -0,0 +1,45 @@
+//! Structured event protocol the World Engine can use to talk to this app
+//! over stdout, instead of us hunting for magic substrings in otherwise
+//! human-readable log lines.
+
+use serde::{Deserialize, Serialize};
+
+/// Prefix a line must carry for its remainder to be parsed as a JSON
+/// [`EngineEvent`] instead of passed through as a plain log line.
+pub const SENTINEL: &str = "@@BIOME@@";
+
+/// A structured event emitted by the engine, one JSON object per line behind
+/// [`SENTINEL`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum EngineEvent {
+    Progress { step: u64, total: u64 },
+    Ready,
+    Error { code: String, message: String },
+    ImageReady { path: String },
+}
+
+/// The Tauri event name each `EngineEvent` variant is emitted under.
+pub fn event_name(event: &EngineEvent) -> &'static str {
+    match event {
+        EngineEvent::Progress { .. } => "engine-progress",
+        EngineEvent::Ready => "engine-event-ready",
+        EngineEvent::Error { .. } => "engine-error",
+        EngineEvent::ImageReady { .. } => "engine-image-ready",
+    }
+}
+
+/// Parse a raw stdout/stderr line as a structured engine event if it carries
+/// the [`SENTINEL`] prefix. Returns `None` for plain lines, or for a
+/// sentinel-prefixed line that fails to parse (logged, not propagated, so a
+/// malformed event doesn't take down the tee thread).
+pub fn parse_line(line: &str) -> Option<EngineEvent> {
+    let payload = line.strip_prefix(SENTINEL)?;
+    match serde_json::from_str(payload) {
+        Ok(event) => Some(event),
+        Err(e) => {
+            log::warn!("Failed to parse structured engine event: {}", e);
+            None
+        }
+    }
+}